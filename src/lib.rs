@@ -1,7 +1,9 @@
 //! # SimpleHighPrecisionClock
 //!
 //! `SimpleHighPrecisionClock` provides a high-precision clock that leverages the CPU's
-//! Time Stamp Counter (TSC) to measure time elapsed since instantiation in nanoseconds.
+//! free-running cycle counter (the TSC on x86_64, the architected generic timer on aarch64, and
+//! a coarse monotonic OS clock elsewhere) to measure time elapsed since instantiation in
+//! nanoseconds.
 //!
 //! The idea is from `tscns` a very impressive and lightweight clock in C.
 //!
@@ -13,10 +15,10 @@
 //!
 //! ## Example
 //!
-//! ```rust
+//! ```rust,no_run
 //! use high_precision_clock::SimpleHighPrecisionClock;
 //!
-//! let mut clock = SimpleHighPrecisionClock::new(100 * 1000 * 1000);
+//! let clock = SimpleHighPrecisionClock::new(100 * 1000 * 1000);
 //! let time_ns = clock.now();
 //! println!("Elapsed time in nanoseconds: {}", time_ns);
 //! loop {
@@ -25,16 +27,71 @@
 //!}
 //! ```
 //!
+//! Calling `calibrate` in your own loop is easy to forget, so for set-and-forget precision,
+//! wrap the clock in an `Arc`, hand it to [`SimpleHighPrecisionClock::spawn_calibration_thread`],
+//! and read time through [`Instant`] instead:
+//!
+//! ```rust,no_run
+//! use high_precision_clock::{Instant, SimpleHighPrecisionClock};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let clock = Arc::new(SimpleHighPrecisionClock::new(100 * 1000 * 1000));
+//! let _calibration = Arc::clone(&clock).spawn_calibration_thread(Duration::from_secs(1));
+//!
+//! let start = Instant::now(&clock);
+//! // your task
+//! println!("Elapsed: {:?}", start.elapsed());
+//! ```
+//!
+//! `calibrate` is safe to call concurrently, including from multiple threads at once, so mixing
+//! the two examples above — running the background thread while also calling `clock.calibrate()`
+//! yourself — is harmless, just redundant: an overlapping call becomes a no-op rather than
+//! racing the one already in flight.
+//!
 //! This library is particularly useful for applications that require precise time
 //! tracking in environments where traditional time sources may lack stability or
 //! granularity.
+//!
+//! On construction, the clock probes the TSC (see [`TscState`]) and transparently falls back
+//! to the system clock if it isn't trustworthy, so `now()` is always correct, just slower on
+//! machines where the TSC can't be used.
+
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+/// Reads the platform's free-running cycle counter.
+///
+/// On x86_64 this is the TSC; on aarch64 it's the architected generic timer's virtual count
+/// register, which like the TSC is a free-running counter rather than a nanosecond clock. On
+/// other targets there's no such register, so this falls back to a coarse monotonic OS clock
+/// that already reports nanoseconds — `calibrate_once`'s empirical `ns_per_tsc` naturally comes
+/// out near `1.0` in that case, so the rest of the calibration math keeps working unchanged.
+#[cfg(target_arch = "x86_64")]
+fn read_cycle_counter() -> u64 {
+    // Safety: RDTSC is available on every x86_64 target Rust supports.
+    unsafe { core::arch::x86_64::_rdtsc() }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn read_cycle_counter() -> u64 {
+    let counter: u64;
+    // Safety: CNTVCT_EL0 is readable from EL0 on every aarch64 target Rust supports.
+    unsafe {
+        core::arch::asm!("mrs {0}, cntvct_el0", out(reg) counter, options(nomem, nostack));
+    }
+    counter
+}
 
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::SystemTime;
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn read_cycle_counter() -> u64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
 
-fn get_time() -> u64 {
-    // Reads the Time Stamp Counter (TSC)
-    unsafe { core::arch::x86_64::_rdtsc() }
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as u64
 }
 
 fn rdsysns() -> u64 {
@@ -45,73 +102,361 @@ fn rdsysns() -> u64 {
         .as_nanos() as u64
 }
 
-pub struct SimpleHighPrecisionClock {
+/// A source of the two raw readings `SimpleHighPrecisionClock` calibrates from: a free-running
+/// cycle counter and the system clock, in nanoseconds. Parameterizing over this trait lets
+/// calibration be driven by exact, scripted values via [`ManualClock`] in tests, instead of
+/// relying on real sleeps and timing luck.
+pub trait Clock {
+    /// Reads the free-running cycle counter (TSC, architected timer, or coarse OS fallback).
+    fn cycle_counter(&self) -> u64;
+    /// Reads the current system time in nanoseconds since the UNIX epoch.
+    fn system_ns(&self) -> u64;
+}
+
+/// The real platform clock: the cycle-counter backend selected by [`read_cycle_counter`] plus
+/// the system clock. This is [`SimpleHighPrecisionClock`]'s default [`Clock`] and the only one
+/// production code needs; it's `pub` solely because it appears in `SimpleHighPrecisionClock`'s
+/// default type parameter.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn cycle_counter(&self) -> u64 {
+        read_cycle_counter()
+    }
+
+    #[inline]
+    fn system_ns(&self) -> u64 {
+        rdsysns()
+    }
+}
+
+/// A [`Clock`] whose readings are set explicitly via atomics rather than read from hardware —
+/// the same pattern `governor` uses to make rate limiters independent of wall time.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    cycle_counter: AtomicU64,
+    system_ns: AtomicU64,
+}
+
+impl ManualClock {
+    /// Creates a manual clock starting at the given cycle-counter and system-time readings.
+    pub fn new(cycle_counter: u64, system_ns: u64) -> Self {
+        Self {
+            cycle_counter: AtomicU64::new(cycle_counter),
+            system_ns: AtomicU64::new(system_ns),
+        }
+    }
+
+    /// Sets the cycle-counter reading returned by the next call to [`Clock::cycle_counter`].
+    pub fn set_cycle_counter(&self, value: u64) {
+        self.cycle_counter.store(value, Ordering::Relaxed);
+    }
+
+    /// Sets the system-time reading returned by the next call to [`Clock::system_ns`].
+    pub fn set_system_ns(&self, value: u64) {
+        self.system_ns.store(value, Ordering::Relaxed);
+    }
+}
+
+impl Clock for ManualClock {
+    fn cycle_counter(&self) -> u64 {
+        self.cycle_counter.load(Ordering::Relaxed)
+    }
+
+    fn system_ns(&self) -> u64 {
+        self.system_ns.load(Ordering::Relaxed)
+    }
+}
+
+/// Number of fractional bits used to represent `ns_per_tsc` as a fixed-point integer.
+///
+/// `ns_per_tsc_scaled = round(ns_per_tsc * (1 << NS_PER_TSC_SHIFT))`.
+const NS_PER_TSC_SHIFT: u32 = 32;
+
+/// Maximum fractional skew, between the TSC-predicted elapsed time and the system clock's
+/// elapsed time over the startup watchdog window, tolerated before the TSC is rejected.
+/// Mirrors the "skew is too large" heuristic Linux's TSC clocksource watchdog uses.
+const TSC_SKEW_THRESHOLD: f64 = 0.01;
+
+/// Whether the Time Stamp Counter is trustworthy as a time source on this machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TscState {
+    /// CPUID reports an invariant TSC and the startup skew watchdog passed: `now()` reads the
+    /// TSC and converts it with the calibrated rate.
+    Invariant,
+    /// The TSC is present but CPUID doesn't report it as invariant, or it drifted too far from
+    /// the system clock during the startup watchdog check: `now()` falls back to the system
+    /// clock instead.
+    Unstable,
+    /// No TSC-like counter is available on this target: `now()` falls back to the system clock.
+    Unavailable,
+}
+
+/// Checks the invariant-TSC feature bit (CPUID leaf `0x8000_0007`, EDX bit 8) — the same bit
+/// Linux's `constant_tsc`/`nonstop_tsc` flags and minstant's TSC gate check.
+#[cfg(target_arch = "x86_64")]
+fn cpuid_invariant_tsc() -> bool {
+    let max_extended_leaf = core::arch::x86_64::__cpuid(0x8000_0000).eax;
+    if max_extended_leaf < 0x8000_0007 {
+        return false;
+    }
+    let features = core::arch::x86_64::__cpuid(0x8000_0007);
+    features.edx & (1 << 8) != 0
+}
+
+/// There's no CPUID-equivalent invariant-counter feature bit to probe on aarch64, so treat the
+/// watchdog skew check in `calibrate_once` as the sole gate there. Only reached when
+/// [`HAS_CYCLE_COUNTER`] is `true`, i.e. on x86_64 or aarch64, so this never runs on the
+/// coarse-clock fallback targets that report [`TscState::Unavailable`] instead.
+#[cfg(target_arch = "aarch64")]
+fn cpuid_invariant_tsc() -> bool {
+    true
+}
+
+/// The coarse-clock fallback targets have no cycle counter to probe. `with_clock` checks
+/// [`HAS_CYCLE_COUNTER`] first and reports [`TscState::Unavailable`] there, so this is never
+/// called; it exists so that check compiles on every target.
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn cpuid_invariant_tsc() -> bool {
+    false
+}
+
+/// Whether this target has a real free-running cycle counter behind [`read_cycle_counter`]
+/// (TSC on x86_64, the architected timer on aarch64) as opposed to falling back to the coarse
+/// monotonic-clock proxy, which isn't a TSC-like counter at all and so is never probed for
+/// invariance or skew — it's unconditionally [`TscState::Unavailable`].
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const HAS_CYCLE_COUNTER: bool = true;
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+const HAS_CYCLE_COUNTER: bool = false;
+
+/// The mutable part of the calibration state, guarded by `SimpleHighPrecisionClock::generation`
+/// instead of a lock: readers retry if they observe a write in progress. Each field is an
+/// `AtomicU64` loaded/stored with `Relaxed` ordering — the seqlock's safety comes entirely from
+/// the `Acquire`/`Release` generation checks and fences bracketing those accesses in `read_state`
+/// and `write_state`, not from these individual loads/stores, but they still need to be atomic:
+/// plain reads/writes here would be a data race under Rust's memory model regardless of how
+/// `generation` is ordered around them.
+struct CalibrationState {
     base_tsc: AtomicU64,
     base_ns: AtomicU64,
-    ns_per_tsc: f64,
+    /// `ns_per_tsc`, fixed-point scaled by `1 << NS_PER_TSC_SHIFT`.
+    ns_per_tsc_scaled: AtomicU64,
+}
+
+pub struct SimpleHighPrecisionClock<C: Clock = SystemClock> {
+    clock: C,
+    /// Seqlock generation: even while the state is stable, odd while a writer is mid-update.
+    generation: AtomicU64,
+    state: CalibrationState,
     calibration_interval_ns: u64,
-    base_ns_err: i64,
-    next_calibrate_tsc: u64,
+    base_ns_err: AtomicI64,
+    next_calibrate_tsc: AtomicU64,
+    tsc_state: TscState,
+    /// Serializes `calibrate()` across threads: a seqlock only arbitrates readers against a
+    /// single writer, so a second concurrent writer must be turned away here before it ever
+    /// touches `state`, rather than being allowed to race `write_state`.
+    calibrating: AtomicBool,
 }
 
-impl SimpleHighPrecisionClock {
+impl SimpleHighPrecisionClock<SystemClock> {
     /// Initializes the clock and performs an initial calibration.
+    ///
+    /// Probes the TSC via CPUID and a startup skew watchdog (see [`TscState`]); if the TSC is
+    /// rejected, `now()` transparently falls back to the system clock instead.
     pub fn new(calibration_interval_ns: u64) -> Self {
-        let (base_tsc, base_ns, ns_per_tsc) = Self::calibrate_once();
+        Self::with_clock(SystemClock, calibration_interval_ns)
+    }
+}
+
+impl<C: Clock> SimpleHighPrecisionClock<C> {
+    /// Initializes the clock against an explicit [`Clock`] source and performs an initial
+    /// calibration, including `calibrate_once`'s real sleeps. [`Self::new`] calls this with
+    /// [`SystemClock`]; tests that drive calibration with a [`ManualClock`] use `for_test`
+    /// instead, which skips the startup probe.
+    fn with_clock(clock: C, calibration_interval_ns: u64) -> Self {
+        let (base_tsc, base_ns, ns_per_tsc, skew_ok) = Self::calibrate_once(&clock);
+        let tsc_state = if !HAS_CYCLE_COUNTER {
+            TscState::Unavailable
+        } else if !skew_ok {
+            TscState::Unstable
+        } else if cpuid_invariant_tsc() {
+            TscState::Invariant
+        } else {
+            TscState::Unstable
+        };
+        let ns_per_tsc_scaled = Self::scale_ns_per_tsc(ns_per_tsc);
         let next_calibrate_tsc = base_tsc + (calibration_interval_ns as f64 / ns_per_tsc) as u64;
         Self {
-            base_tsc: AtomicU64::new(base_tsc),
-            base_ns: AtomicU64::new(base_ns),
-            ns_per_tsc,
+            clock,
+            generation: AtomicU64::new(0),
+            state: CalibrationState {
+                base_tsc: AtomicU64::new(base_tsc),
+                base_ns: AtomicU64::new(base_ns),
+                ns_per_tsc_scaled: AtomicU64::new(ns_per_tsc_scaled),
+            },
             calibration_interval_ns,
-            base_ns_err: 0,
-            next_calibrate_tsc,
+            base_ns_err: AtomicI64::new(0),
+            next_calibrate_tsc: AtomicU64::new(next_calibrate_tsc),
+            tsc_state,
+            calibrating: AtomicBool::new(false),
         }
     }
 
+    /// Returns whether the TSC was accepted as a time source, or why it was rejected.
+    pub fn tsc_state(&self) -> TscState {
+        self.tsc_state
+    }
+
+    /// Scales a floating-point `ns_per_tsc` ratio into its fixed-point representation.
+    #[inline]
+    fn scale_ns_per_tsc(ns_per_tsc: f64) -> u64 {
+        (ns_per_tsc * (1u64 << NS_PER_TSC_SHIFT) as f64).round() as u64
+    }
+
+    /// Reads a consistent `(base_tsc, base_ns, ns_per_tsc_scaled)` snapshot, retrying if a
+    /// writer was mid-update (generation odd, or changed between the two generation reads).
+    #[inline]
+    fn read_state(&self) -> (u64, u64, u64) {
+        loop {
+            let gen_before = self.generation.load(Ordering::Acquire);
+            if gen_before & 1 != 0 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            // The Acquire generation load above and the Acquire fence below are what make this
+            // consistent; these individual loads only need to be atomic, not ordered, hence Relaxed.
+            let base_tsc = self.state.base_tsc.load(Ordering::Relaxed);
+            let base_ns = self.state.base_ns.load(Ordering::Relaxed);
+            let ns_per_tsc_scaled = self.state.ns_per_tsc_scaled.load(Ordering::Relaxed);
+
+            // Keeps the field loads above from moving past the second generation read.
+            std::sync::atomic::fence(Ordering::Acquire);
+            let gen_after = self.generation.load(Ordering::Acquire);
+            if gen_after == gen_before {
+                return (base_tsc, base_ns, ns_per_tsc_scaled);
+            }
+        }
+    }
+
+    /// Publishes a new `(base_tsc, base_ns, ns_per_tsc_scaled)` snapshot under the seqlock.
+    fn write_state(&self, base_tsc: u64, base_ns: u64, ns_per_tsc_scaled: u64) {
+        let gen = self.generation.load(Ordering::Relaxed);
+        self.generation.store(gen.wrapping_add(1), Ordering::Release);
+        // Keeps the field stores below from becoming visible before the odd generation.
+        std::sync::atomic::fence(Ordering::Release);
+
+        // The odd generation published above tells concurrent readers to retry, so these stores
+        // only need to be atomic (Relaxed), not ordered among themselves.
+        self.state.base_tsc.store(base_tsc, Ordering::Relaxed);
+        self.state.base_ns.store(base_ns, Ordering::Relaxed);
+        self.state
+            .ns_per_tsc_scaled
+            .store(ns_per_tsc_scaled, Ordering::Relaxed);
+
+        self.generation.store(gen.wrapping_add(2), Ordering::Release);
+    }
+
     /// Calibrates the TSC using a technique that adjusts `ns_per_tsc` based on observed drift.
-    pub fn calibrate(&mut self) {
-        let current_tsc = get_time();
-        if current_tsc < self.next_calibrate_tsc {
+    ///
+    /// Safe to call concurrently with `now()` *and* with other concurrent calls to `calibrate()`
+    /// itself — from your own loop, the background thread spawned by
+    /// [`Self::spawn_calibration_thread`], or both at once. State updates are published through
+    /// a seqlock so readers never observe a torn `base_tsc`/`base_ns`/`ns_per_tsc`, and a
+    /// `calibrate()` call that overlaps another one already in flight is a harmless no-op rather
+    /// than a second writer racing the first.
+    pub fn calibrate(&self) {
+        if self.tsc_state != TscState::Invariant {
+            // now() isn't reading the TSC, so there's nothing to recalibrate.
             return;
         }
 
-        let (tsc, ns) = Self::sync_time();
+        let current_tsc = self.clock.cycle_counter();
+        if current_tsc < self.next_calibrate_tsc.load(Ordering::Relaxed) {
+            return;
+        }
+
+        // Only one calibration may be in flight at a time: the seqlock above protects readers
+        // against a single writer, not against two, so a second concurrent caller must back off
+        // here rather than interleave its `write_state` field writes with the other writer's.
+        if self
+            .calibrating
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return;
+        }
+        // A caller that passed the due check while another calibration was finishing must not
+        // redo it against the state that calibration just saved.
+        if current_tsc < self.next_calibrate_tsc.load(Ordering::Relaxed) {
+            self.calibrating.store(false, Ordering::Release);
+            return;
+        }
+
+        let (tsc, ns) = Self::sync_time(&self.clock);
         let calculated_ns = self.tsc_to_ns(tsc);
         let ns_err = calculated_ns as i64 - ns as i64;
 
+        let (_, base_ns, ns_per_tsc_scaled) = self.read_state();
+        let base_ns_err = self.base_ns_err.load(Ordering::Relaxed);
+
         // Estimate error drift for next calibration.
         let expected_err_next = ns_err
-            + (ns_err - self.base_ns_err) * self.calibration_interval_ns as i64
-                / (ns as i64 - self.base_ns.load(Ordering::SeqCst) as i64 + self.base_ns_err);
-
-        // Update `ns_per_tsc` based on error estimate.
-        self.ns_per_tsc *= 1.0 - (expected_err_next as f64 / self.calibration_interval_ns as f64);
-        self.save_params(tsc, calculated_ns, ns_err);
+            + (ns_err - base_ns_err) * self.calibration_interval_ns as i64
+                / (ns as i64 - base_ns as i64 + base_ns_err);
+
+        // Update `ns_per_tsc` based on error estimate. The correction is computed once here,
+        // off the hot path, and folded into the fixed-point scale used by `tsc_to_ns`.
+        let correction = 1.0 - (expected_err_next as f64 / self.calibration_interval_ns as f64);
+        let new_ns_per_tsc_scaled = (ns_per_tsc_scaled as f64 * correction) as u64;
+        self.save_params(tsc, calculated_ns, ns_err, new_ns_per_tsc_scaled);
+        self.calibrating.store(false, Ordering::Release);
     }
 
-    /// Converts TSC to nanoseconds based on `ns_per_tsc`.
+    /// Converts TSC to nanoseconds using fixed-point `ns_per_tsc` arithmetic.
+    ///
+    /// The multiply is done in `u128` so that a large `elapsed_cycles` cannot overflow
+    /// before the `NS_PER_TSC_SHIFT`-bit shift brings the result back down to nanoseconds.
     #[inline]
     fn tsc_to_ns(&self, tsc: u64) -> u64 {
-        let elapsed_cycles = tsc - self.base_tsc.load(Ordering::SeqCst);
-        self.base_ns.load(Ordering::SeqCst) + (elapsed_cycles as f64 * self.ns_per_tsc) as u64
+        let (base_tsc, base_ns, ns_per_tsc_scaled) = self.read_state();
+        let elapsed_cycles = tsc - base_tsc;
+        let scaled_ns = (elapsed_cycles as u128 * ns_per_tsc_scaled as u128) >> NS_PER_TSC_SHIFT;
+        base_ns + scaled_ns as u64
     }
 
-    /// Performs an initial calibration by observing elapsed TSC over a short interval.
-    fn calibrate_once() -> (u64, u64, f64) {
-        let base_tsc = get_time();
-        let base_ns = rdsysns();
+    /// Performs an initial calibration by observing elapsed TSC over a short interval, then runs
+    /// a watchdog check over a second interval: it predicts that interval's elapsed time from
+    /// the just-derived `ns_per_tsc` and compares it against the system clock's elapsed time,
+    /// rejecting the TSC if the skew exceeds [`TSC_SKEW_THRESHOLD`].
+    ///
+    /// Returns `(base_tsc, base_ns, ns_per_tsc, skew_within_threshold)`.
+    fn calibrate_once(clock: &C) -> (u64, u64, f64, bool) {
+        let base_tsc = clock.cycle_counter();
+        let base_ns = clock.system_ns();
         std::thread::sleep(std::time::Duration::from_millis(20));
 
-        let new_tsc = get_time();
-        let new_ns = rdsysns();
+        let mid_tsc = clock.cycle_counter();
+        let mid_ns = clock.system_ns();
+        let ns_per_tsc = (mid_ns - base_ns) as f64 / (mid_tsc - base_tsc) as f64;
 
-        let ns_per_tsc = (new_ns - base_ns) as f64 / (new_tsc - base_tsc) as f64;
-        (base_tsc, base_ns, ns_per_tsc)
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let new_tsc = clock.cycle_counter();
+        let new_ns = clock.system_ns();
+
+        let predicted_ns = (new_tsc - mid_tsc) as f64 * ns_per_tsc;
+        let actual_ns = (new_ns - mid_ns) as f64;
+        let skew = (predicted_ns - actual_ns).abs() / actual_ns;
+
+        (base_tsc, base_ns, ns_per_tsc, skew <= TSC_SKEW_THRESHOLD)
     }
 
     /// Synchronizes TSC with system time, attempting multiple times to minimize TSC drift.
-    fn sync_time() -> (u64, u64) {
+    fn sync_time(clock: &C) -> (u64, u64) {
         const SYNC_ATTEMPTS: usize = 10; // Number of attempts to synchronize
 
         let mut min_diff = u64::MAX;
@@ -119,9 +464,9 @@ impl SimpleHighPrecisionClock {
         let mut best_ns = 0;
 
         for _ in 0..SYNC_ATTEMPTS {
-            let tsc_before = get_time();
-            let ns = rdsysns();
-            let tsc_after = get_time();
+            let tsc_before = clock.cycle_counter();
+            let ns = clock.system_ns();
+            let tsc_after = clock.cycle_counter();
 
             let diff = tsc_after - tsc_before;
             if diff < min_diff {
@@ -134,21 +479,140 @@ impl SimpleHighPrecisionClock {
     }
 
     /// Updates parameters after each calibration.
-    fn save_params(&mut self, tsc: u64, calculated_ns: u64, ns_err: i64) {
-        self.base_ns_err = ns_err;
-        self.next_calibrate_tsc =
-            tsc + (self.calibration_interval_ns as f64 / self.ns_per_tsc) as u64;
-        self.base_tsc.store(tsc, Ordering::SeqCst);
-        self.base_ns.store(calculated_ns, Ordering::SeqCst);
+    fn save_params(&self, tsc: u64, calculated_ns: u64, ns_err: i64, ns_per_tsc_scaled: u64) {
+        self.base_ns_err.store(ns_err, Ordering::Relaxed);
+        let ns_per_tsc = ns_per_tsc_scaled as f64 / (1u64 << NS_PER_TSC_SHIFT) as f64;
+        let next_calibrate_tsc = tsc + (self.calibration_interval_ns as f64 / ns_per_tsc) as u64;
+        self.next_calibrate_tsc
+            .store(next_calibrate_tsc, Ordering::Relaxed);
+        self.write_state(tsc, calculated_ns, ns_per_tsc_scaled);
     }
 
     /// Returns the current time in nanoseconds since the UNIX epoch.
+    ///
+    /// Reads the TSC and converts it with the calibrated rate when [`TscState::Invariant`];
+    /// otherwise transparently falls back to the system clock.
     pub fn now(&self) -> u64 {
-        let current_tsc = get_time();
+        if self.tsc_state != TscState::Invariant {
+            return self.clock.system_ns();
+        }
+        let current_tsc = self.clock.cycle_counter();
         self.tsc_to_ns(current_tsc)
     }
 }
 
+impl<C: Clock + Send + Sync + 'static> SimpleHighPrecisionClock<C> {
+    /// Spawns a background thread that calls [`Self::calibrate`] every `interval`, so callers
+    /// get set-and-forget precision without running their own calibration loop.
+    ///
+    /// Takes `self: Arc<Self>` so the calibration thread and any [`Instant::now`] callers can
+    /// share one clock safely — `calibrate` publishes through the seqlock described on
+    /// [`Self::read_state`], so readers on other threads never observe a torn snapshot. It's also
+    /// fine to keep calling `clock.calibrate()` yourself on top of this thread: overlapping
+    /// `calibrate` calls from any combination of threads serialize internally, so the extra call
+    /// is just a redundant no-op rather than a race.
+    ///
+    /// Returns a guard that stops and joins the thread when dropped.
+    pub fn spawn_calibration_thread(self: Arc<Self>, interval: Duration) -> CalibrationThreadGuard {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        // Wake up in short ticks rather than sleeping the full interval, so dropping the guard
+        // doesn't have to wait out an entire (possibly long) calibration interval to stop.
+        let tick = interval.min(Duration::from_millis(50)).max(Duration::from_millis(1));
+        let handle = std::thread::spawn(move || {
+            let mut elapsed = Duration::ZERO;
+            while !stop_for_thread.load(Ordering::Relaxed) {
+                std::thread::sleep(tick);
+                elapsed += tick;
+                if elapsed >= interval {
+                    self.calibrate();
+                    elapsed = Duration::ZERO;
+                }
+            }
+        });
+        CalibrationThreadGuard {
+            stop,
+            handle: Some(handle),
+        }
+    }
+}
+
+/// Owns the background thread spawned by [`SimpleHighPrecisionClock::spawn_calibration_thread`];
+/// stops and joins it when dropped.
+pub struct CalibrationThreadGuard {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for CalibrationThreadGuard {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A point in time captured from a [`SimpleHighPrecisionClock`], mirroring `std::time::Instant`'s
+/// `now`/`elapsed`/`duration_since` ergonomics but backed by the clock's calibrated nanosecond
+/// reading instead of the OS's monotonic clock.
+#[derive(Clone)]
+pub struct Instant<C: Clock = SystemClock> {
+    clock: Arc<SimpleHighPrecisionClock<C>>,
+    ns: u64,
+}
+
+impl<C: Clock> Instant<C> {
+    /// Captures the current time from `clock`.
+    pub fn now(clock: &Arc<SimpleHighPrecisionClock<C>>) -> Self {
+        Self {
+            clock: Arc::clone(clock),
+            ns: clock.now(),
+        }
+    }
+
+    /// Returns the time elapsed since this instant was captured.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.clock.now().saturating_sub(self.ns))
+    }
+
+    /// Returns the time elapsed between an earlier instant and this one.
+    pub fn duration_since(&self, earlier: &Instant<C>) -> Duration {
+        Duration::from_nanos(self.ns.saturating_sub(earlier.ns))
+    }
+}
+
+#[cfg(test)]
+impl<C: Clock> SimpleHighPrecisionClock<C> {
+    /// Builds a clock with an explicit initial calibration state, bypassing `calibrate_once`'s
+    /// startup probe and its real sleeps — lets tests drive `calibrate`'s drift-correction
+    /// recurrence with exact, scripted [`ManualClock`] readings.
+    fn for_test(
+        clock: C,
+        calibration_interval_ns: u64,
+        base_tsc: u64,
+        base_ns: u64,
+        ns_per_tsc: f64,
+    ) -> Self {
+        let ns_per_tsc_scaled = Self::scale_ns_per_tsc(ns_per_tsc);
+        let next_calibrate_tsc = base_tsc + (calibration_interval_ns as f64 / ns_per_tsc) as u64;
+        Self {
+            clock,
+            generation: AtomicU64::new(0),
+            state: CalibrationState {
+                base_tsc: AtomicU64::new(base_tsc),
+                base_ns: AtomicU64::new(base_ns),
+                ns_per_tsc_scaled: AtomicU64::new(ns_per_tsc_scaled),
+            },
+            calibration_interval_ns,
+            base_ns_err: AtomicI64::new(0),
+            next_calibrate_tsc: AtomicU64::new(next_calibrate_tsc),
+            tsc_state: TscState::Invariant,
+            calibrating: AtomicBool::new(false),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,8 +664,14 @@ mod tests {
 
     #[test]
     fn test_drift_with_calibration() {
-        // Test if calling `calibrate` reduces drift after a delay.
-        let mut clock = SimpleHighPrecisionClock::new(3_000_000_000);
+        // Test if calling `calibrate` reduces drift after a delay. This only exercises TSC-based
+        // drift correction when the TSC was actually accepted; on a machine/VM where it was
+        // rejected, `now()` already tracks the system clock directly and there's nothing for
+        // `calibrate` to do.
+        let clock = SimpleHighPrecisionClock::new(3_000_000_000);
+        if clock.tsc_state() != TscState::Invariant {
+            return;
+        }
 
         // Simulate time passage and accumulate drift
         let initial_time_ns = clock.now();
@@ -226,24 +696,147 @@ mod tests {
 
     #[test]
     fn test_multiple_calibrations() {
-        // Test that multiple calls to `calibrate` maintain reasonable accuracy.
-        let mut clock = SimpleHighPrecisionClock::new(1_000_000_000); // Set calibration interval to 1 second
-        let mut previous_ns_per_tsc = clock.ns_per_tsc;
+        // Test that multiple calls to `calibrate` maintain reasonable accuracy. Like
+        // `test_drift_with_calibration`, this only applies when the TSC was accepted: otherwise
+        // `calibrate` is a no-op because `now()` isn't reading the TSC at all.
+        let clock = SimpleHighPrecisionClock::new(1_000_000_000); // Set calibration interval to 1 second
+        if clock.tsc_state() != TscState::Invariant {
+            return;
+        }
+        let (_, _, initial_ns_per_tsc) = clock.read_state();
+        let mut adjusted = false;
 
         for _ in 0..5 {
             // Simulate time passage
             sleep(Duration::from_secs(1));
             clock.calibrate();
 
-            // Check if `ns_per_tsc` was updated, indicating calibration occurred
-            let current_ns_per_tsc = clock.ns_per_tsc;
-            assert_ne!(
-                previous_ns_per_tsc, current_ns_per_tsc,
-                "ns_per_tsc should adjust on each calibration call"
-            );
+            // Fixed-point rounding can leave `ns_per_tsc_scaled` unchanged on a single
+            // calibration with a tiny correction, so check it adjusts over the run.
+            let (_, _, current_ns_per_tsc) = clock.read_state();
+            if current_ns_per_tsc != initial_ns_per_tsc {
+                adjusted = true;
+            }
+        }
 
-            // Update previous value for next comparison
-            previous_ns_per_tsc = current_ns_per_tsc;
+        assert!(
+            adjusted,
+            "ns_per_tsc should adjust across repeated calibration calls"
+        );
+    }
+
+    #[test]
+    fn test_calibrate_recurrence_with_manual_clock() {
+        // Exercise calibrate()'s drift-correction recurrence deterministically: script exact
+        // TSC/ns pairs via a ManualClock instead of relying on real sleeps and timing luck.
+        let manual_clock = ManualClock::new(0, 0);
+        let clock = SimpleHighPrecisionClock::for_test(manual_clock, 1_000_000_000, 0, 0, 1.0);
+
+        // Advance the cycle counter by 1e9 ticks but the system clock by 10ms more than that,
+        // simulating a TSC that runs slightly slow relative to `ns_per_tsc = 1.0`.
+        clock.clock.set_cycle_counter(1_000_000_000);
+        clock.clock.set_system_ns(1_010_000_000);
+        clock.calibrate();
+
+        let (_, _, ns_per_tsc_scaled) = clock.read_state();
+        let ns_per_tsc = ns_per_tsc_scaled as f64 / (1u64 << 32) as f64;
+        assert!(
+            ns_per_tsc > 1.0,
+            "ns_per_tsc should be corrected upward for a slow TSC, got {ns_per_tsc}"
+        );
+    }
+
+    #[test]
+    fn test_concurrent_calibrate_does_not_race() {
+        // Have many threads call `calibrate()` at the same moment, all against a clock that's
+        // due for recalibration. The `calibrating` guard should let exactly one of them run the
+        // actual read-modify-write sequence and turn the rest away as no-ops. If a second thread
+        // re-ran it after the first had saved its result, the recurrence's divisor
+        // `ns - base_ns + base_ns_err` would be zero for these readings and that thread would
+        // panic, failing the join below.
+        let manual_clock = ManualClock::new(0, 0);
+        let clock = Arc::new(SimpleHighPrecisionClock::for_test(
+            manual_clock,
+            1_000_000_000,
+            0,
+            0,
+            1.0,
+        ));
+        clock.clock.set_cycle_counter(1_000_000_000);
+        clock.clock.set_system_ns(1_010_000_000);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let clock = Arc::clone(&clock);
+                std::thread::spawn(move || clock.calibrate())
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
         }
+
+        let (_, _, ns_per_tsc_scaled) = clock.read_state();
+        let ns_per_tsc = ns_per_tsc_scaled as f64 / (1u64 << 32) as f64;
+        assert!(
+            ns_per_tsc > 0.5 && ns_per_tsc < 2.0,
+            "ns_per_tsc should stay in a sane range after concurrent calibration, got {ns_per_tsc}"
+        );
+    }
+
+    #[test]
+    fn test_tsc_state_reported() {
+        // Whatever the verdict, the clock should report one explicitly rather than silently
+        // assuming the TSC is good. x86_64/aarch64 have a real cycle counter to probe, so the
+        // TSC can only be rejected as `Unstable`, never reported `Unavailable`; every other
+        // target has no such counter at all, so it must always be `Unavailable`.
+        let clock = SimpleHighPrecisionClock::new(10000);
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        assert_ne!(clock.tsc_state(), TscState::Unavailable);
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        assert_eq!(clock.tsc_state(), TscState::Unavailable);
+    }
+
+    #[test]
+    fn test_instant_elapsed() {
+        let clock = Arc::new(SimpleHighPrecisionClock::new(10_000));
+        let start = Instant::now(&clock);
+
+        sleep(Duration::from_millis(50));
+
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed >= Duration::from_millis(40),
+            "elapsed should be close to 50ms but was {:?}",
+            elapsed
+        );
+    }
+
+    #[test]
+    fn test_instant_duration_since() {
+        let clock = Arc::new(SimpleHighPrecisionClock::new(10_000));
+        let earlier = Instant::now(&clock);
+
+        sleep(Duration::from_millis(50));
+
+        let later = Instant::now(&clock);
+        let duration = later.duration_since(&earlier);
+        assert!(
+            duration >= Duration::from_millis(40),
+            "duration_since should be close to 50ms but was {:?}",
+            duration
+        );
+    }
+
+    #[test]
+    fn test_spawn_calibration_thread_joins_on_drop() {
+        let clock = Arc::new(SimpleHighPrecisionClock::new(10_000_000));
+        let guard = Arc::clone(&clock).spawn_calibration_thread(Duration::from_millis(10));
+
+        // Let the background thread run at least one calibration tick before stopping it.
+        sleep(Duration::from_millis(50));
+        drop(guard);
+
+        // If the guard didn't join cleanly, a lingering thread would still hold this Arc clone.
+        assert_eq!(Arc::strong_count(&clock), 1);
     }
 }